@@ -0,0 +1,86 @@
+#![forbid(unsafe_code)]
+#![rustfmt::skip]
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C, align(8))]
+pub struct r#FixedSize {
+  pub r#value1: i64,
+  pub r#value2: i64,
+  pub r#value3: f64,
+  pub r#value4: f64,
+}
+impl Default for r#FixedSize {
+  fn default() -> Self {
+    Self {
+      r#value1: 0,
+      r#value2: 0,
+      r#value3: 0.0f64,
+      r#value4: 0.0f64,
+    }
+  }
+}
+impl r#FixedSize {
+  /// On-wire size of the fixed layout, in bytes. Every field is 8 bytes wide and
+  /// 8-byte aligned under `#[repr(C, align(8))]`, so there is no inter-field
+  /// padding and this matches `size_of::<Self>()` exactly.
+  const BYTE_SIZE: usize = 32;
+}
+const _: () = assert!(std::mem::size_of::<r#FixedSize>() == r#FixedSize::BYTE_SIZE);
+impl binder::Parcelable for r#FixedSize {
+  fn write_to_parcel(&self, parcel: &mut binder::binder_impl::BorrowedParcel) -> std::result::Result<(), binder::StatusCode> {
+    let mut bytes = [0u8; Self::BYTE_SIZE];
+    bytes[0..8].copy_from_slice(&self.r#value1.to_ne_bytes());
+    bytes[8..16].copy_from_slice(&self.r#value2.to_ne_bytes());
+    bytes[16..24].copy_from_slice(&self.r#value3.to_ne_bytes());
+    bytes[24..32].copy_from_slice(&self.r#value4.to_ne_bytes());
+    parcel.write_slice(&bytes)
+  }
+  fn read_from_parcel(&mut self, parcel: &binder::binder_impl::BorrowedParcel) -> std::result::Result<(), binder::StatusCode> {
+    let bytes = parcel.read_slice(Self::BYTE_SIZE).ok_or(binder::StatusCode::BAD_VALUE)?;
+    self.r#value1 = i64::from_ne_bytes(bytes[0..8].try_into().unwrap());
+    self.r#value2 = i64::from_ne_bytes(bytes[8..16].try_into().unwrap());
+    self.r#value3 = f64::from_ne_bytes(bytes[16..24].try_into().unwrap());
+    self.r#value4 = f64::from_ne_bytes(bytes[24..32].try_into().unwrap());
+    Ok(())
+  }
+}
+binder::impl_serialize_for_parcelable!(r#FixedSize);
+binder::impl_deserialize_for_parcelable!(r#FixedSize);
+impl binder::binder_impl::SerializeArray for r#FixedSize {
+  fn serialize_array(slice: &[Self], parcel: &mut binder::binder_impl::BorrowedParcel) -> std::result::Result<(), binder::StatusCode> {
+    parcel.write(&(slice.len() as i32))?;
+    let mut bytes = Vec::with_capacity(slice.len() * Self::BYTE_SIZE);
+    for item in slice {
+      bytes.extend_from_slice(&item.r#value1.to_ne_bytes());
+      bytes.extend_from_slice(&item.r#value2.to_ne_bytes());
+      bytes.extend_from_slice(&item.r#value3.to_ne_bytes());
+      bytes.extend_from_slice(&item.r#value4.to_ne_bytes());
+    }
+    parcel.write_slice(&bytes)
+  }
+}
+impl binder::binder_impl::DeserializeArray for r#FixedSize {
+  fn deserialize_array(parcel: &binder::binder_impl::BorrowedParcel) -> std::result::Result<Option<Vec<Self>>, binder::StatusCode> {
+    let len: i32 = parcel.read()?;
+    if len < 0 {
+      return Ok(None);
+    }
+    let len = len as usize;
+    let bytes = parcel.read_slice(len * Self::BYTE_SIZE).ok_or(binder::StatusCode::BAD_VALUE)?;
+    let mut result = Vec::with_capacity(len);
+    for chunk in bytes.chunks_exact(Self::BYTE_SIZE) {
+      result.push(Self {
+        r#value1: i64::from_ne_bytes(chunk[0..8].try_into().unwrap()),
+        r#value2: i64::from_ne_bytes(chunk[8..16].try_into().unwrap()),
+        r#value3: f64::from_ne_bytes(chunk[16..24].try_into().unwrap()),
+        r#value4: f64::from_ne_bytes(chunk[24..32].try_into().unwrap()),
+      });
+    }
+    Ok(Some(result))
+  }
+}
+impl binder::binder_impl::ParcelableMetadata for r#FixedSize {
+  fn get_descriptor() -> &'static str { "android.aidl.tests.FixedSize" }
+}
+pub(crate) mod mangled {
+ pub use super::r#FixedSize as _7_android_4_aidl_5_tests_9_FixedSize;
+}
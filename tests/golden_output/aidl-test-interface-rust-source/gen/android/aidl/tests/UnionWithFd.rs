@@ -1,5 +1,6 @@
 #![forbid(unsafe_code)]
 #![rustfmt::skip]
+use std::os::unix::io::AsRawFd;
 #[derive(Debug)]
 pub enum r#UnionWithFd {
   Num(i32),
@@ -10,6 +11,70 @@ impl Default for r#UnionWithFd {
     Self::Num(0)
   }
 }
+impl r#UnionWithFd {
+  pub fn get_tag(&self) -> r#Tag::r#Tag {
+    match self {
+      Self::Num(_) => r#Tag::r#Tag::num,
+      Self::Pfd(_) => r#Tag::r#Tag::pfd,
+    }
+  }
+  pub fn with_num(v: i32) -> Self {
+    Self::Num(v)
+  }
+  pub fn num(&self) -> Option<&i32> {
+    match self {
+      Self::Num(v) => Some(v),
+      _ => None,
+    }
+  }
+  pub fn set_num(&mut self, v: i32) {
+    *self = Self::Num(v);
+  }
+  pub fn with_pfd(v: Option<binder::ParcelFileDescriptor>) -> Self {
+    Self::Pfd(v)
+  }
+  pub fn pfd(&self) -> Option<&Option<binder::ParcelFileDescriptor>> {
+    match self {
+      Self::Pfd(v) => Some(v),
+      _ => None,
+    }
+  }
+  pub fn set_pfd(&mut self, v: Option<binder::ParcelFileDescriptor>) {
+    *self = Self::Pfd(v);
+  }
+}
+impl Clone for r#UnionWithFd {
+  /// Note that a clone of the `Pfd` variant duplicates the underlying file descriptor
+  /// via `dup(2)`. This panics if the duplication fails. Because `PartialEq` below
+  /// compares `Pfd` by raw fd number rather than fd identity, a cloned value is a
+  /// distinct, open-and-valid duplicate of the descriptor but will *not* compare equal
+  /// to the original: `x.clone() != x` for any `Pfd(Some(_))` value. `Pfd(None)` clones to
+  /// `Pfd(None)`, which compares equal to the original.
+  fn clone(&self) -> Self {
+    match self {
+      Self::Num(v) => Self::Num(v.clone()),
+      Self::Pfd(v) => Self::Pfd(v.as_ref().map(|pfd| {
+        binder::ParcelFileDescriptor::new(pfd.as_ref().try_clone().expect("Failed to dup ParcelFileDescriptor"))
+      })),
+    }
+  }
+}
+impl PartialEq for r#UnionWithFd {
+  /// Compares `Pfd` variants by raw fd number, not by the file/open-file-description
+  /// the fd identifies. Two different fds that happen to reference the same file are
+  /// unequal here, and a dup of the same fd (e.g. from `Clone`) is also unequal, since
+  /// `dup(2)` always returns a new fd number. Callers that need identity or content
+  /// equality across a `Clone` should not rely on this impl.
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (Self::Num(a), Self::Num(b)) => a == b,
+      (Self::Pfd(a), Self::Pfd(b)) => {
+        a.as_ref().map(|pfd| pfd.as_raw_fd()) == b.as_ref().map(|pfd| pfd.as_raw_fd())
+      }
+      _ => false,
+    }
+  }
+}
 impl binder::Parcelable for r#UnionWithFd {
   fn write_to_parcel(&self, parcel: &mut binder::binder_impl::BorrowedParcel) -> std::result::Result<(), binder::StatusCode> {
     match self {
@@ -43,6 +108,14 @@ impl binder::Parcelable for r#UnionWithFd {
     }
   }
 }
+impl std::fmt::Display for r#UnionWithFd {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Num(v) => write!(f, "UnionWithFd{{num: {}}}", v),
+      Self::Pfd(v) => write!(f, "UnionWithFd{{pfd: {}}}", v.as_ref().map(|pfd| pfd.as_raw_fd().to_string()).unwrap_or_else(|| "<fd>".to_string())),
+    }
+  }
+}
 binder::impl_serialize_for_parcelable!(r#UnionWithFd);
 binder::impl_deserialize_for_parcelable!(r#UnionWithFd);
 impl binder::binder_impl::ParcelableMetadata for r#UnionWithFd {
@@ -57,6 +130,15 @@ pub mod r#Tag {
       r#pfd = 1,
     }
   }
+  impl std::fmt::Display for r#Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      match self.0 {
+        0 => write!(f, "num"),
+        1 => write!(f, "pfd"),
+        v => write!(f, "{}", v),
+      }
+    }
+  }
 }
 pub(crate) mod mangled {
  pub use super::r#UnionWithFd as _7_android_4_aidl_5_tests_11_UnionWithFd;
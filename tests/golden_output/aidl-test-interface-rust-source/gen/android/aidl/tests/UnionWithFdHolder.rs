@@ -0,0 +1,30 @@
+#![forbid(unsafe_code)]
+#![rustfmt::skip]
+#[derive(Debug)]
+pub struct r#UnionWithFdHolder {
+  pub r#u: Option<crate::mangled::_7_android_4_aidl_5_tests_11_UnionWithFd>,
+}
+impl Default for r#UnionWithFdHolder {
+  fn default() -> Self {
+    Self {
+      r#u: None,
+    }
+  }
+}
+impl binder::Parcelable for r#UnionWithFdHolder {
+  fn write_to_parcel(&self, parcel: &mut binder::binder_impl::BorrowedParcel) -> std::result::Result<(), binder::StatusCode> {
+    parcel.write(&self.r#u)
+  }
+  fn read_from_parcel(&mut self, parcel: &binder::binder_impl::BorrowedParcel) -> std::result::Result<(), binder::StatusCode> {
+    self.r#u = parcel.read()?;
+    Ok(())
+  }
+}
+binder::impl_serialize_for_parcelable!(r#UnionWithFdHolder);
+binder::impl_deserialize_for_parcelable!(r#UnionWithFdHolder);
+impl binder::binder_impl::ParcelableMetadata for r#UnionWithFdHolder {
+  fn get_descriptor() -> &'static str { "android.aidl.tests.UnionWithFdHolder" }
+}
+pub(crate) mod mangled {
+ pub use super::r#UnionWithFdHolder as _7_android_4_aidl_5_tests_17_UnionWithFdHolder;
+}